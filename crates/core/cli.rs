@@ -64,7 +64,7 @@ pub(crate) struct LogArgs {
     #[arg(long)]
     pub(crate) to: Option<String>,
 
-    /// Task names
+    /// Task names or uuids
     #[arg(short, long)]
     pub(crate) task: Vec<String>,
 
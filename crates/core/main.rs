@@ -86,6 +86,9 @@ fn main() {
                     stop::Error::NoTasks => {
                         eprintln!("No tasks to stop");
                     }
+                    stop::Error::SqlError(err) => {
+                        eprintln!("{err}");
+                    }
                 }
                 std::process::exit(1);
             });
@@ -41,6 +41,8 @@ pub fn event(s: &ShiftDb, opts: &Opts) -> Result<TaskEvent, Error> {
 pub enum UpdateEventError {
     #[error("Could not update event with id {0}")]
     NotUpdated(String),
+    #[error(transparent)]
+    SqlError(#[from] rusqlite::Error),
 }
 
 pub fn update(
@@ -49,19 +51,15 @@ pub fn update(
     updated_event: TaskEvent,
 ) -> Result<(), UpdateEventError> {
     let query = "UPDATE task_events SET name = ?1, state = ?2, time = ?3 WHERE id = ?4";
-    match s
-        .conn
-        .execute(
-            query,
-            params![
-                updated_event.name,
-                updated_event.state,
-                updated_event.time,
-                event.id
-            ],
-        )
-        .expect("SQL statement is valid")
-    {
+    match s.conn.execute(
+        query,
+        params![
+            updated_event.name,
+            updated_event.state,
+            updated_event.time,
+            event.id
+        ],
+    )? {
         0 => Err(UpdateEventError::NotUpdated(event.id)),
 
         1 => Ok(()),
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
 
-use crate::{ShiftDb, TaskEvent, TaskSession, TaskState};
+use crate::{matches_uid, ShiftDb, TaskEvent, TaskSession, TaskState};
 
 #[derive(Debug, Error)]
 pub enum Error {
@@ -166,9 +166,11 @@ pub fn events(s: &ShiftDb, opts: &Opts) -> Result<Vec<TaskEvent>, Error> {
         events.map(|e| e.expect("Database corrupt, could not parse event from database"));
 
     let res = if !opts.tasks.is_empty() {
-        let filtered = parsed_events
-            .into_iter()
-            .filter(|t| opts.tasks.contains(&t.name));
+        let filtered = parsed_events.into_iter().filter(|e| {
+            opts.tasks
+                .iter()
+                .any(|uid| matches_uid(uid, &e.name, &e.session))
+        });
         if let Some(count) = opts.count {
             filtered.take(count).collect()
         } else {
@@ -180,3 +182,47 @@ pub fn events(s: &ShiftDb, opts: &Opts) -> Result<Vec<TaskEvent>, Error> {
 
     Ok(res)
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        commands::{
+            stop::{stop, StopOpts},
+            test::start_with_name,
+        },
+        ShiftDb,
+    };
+
+    use super::{events, Opts};
+
+    #[test]
+    fn filter_by_session_suffix() {
+        let s = ShiftDb::new("");
+
+        let first = start_with_name(&s, "task1");
+        let opts = StopOpts {
+            uid: Some("task1".to_string()),
+            ..Default::default()
+        };
+        stop(&s, &opts).unwrap();
+        start_with_name(&s, "task1");
+        start_with_name(&s, "task2");
+
+        let suffix = &first.session[first.session.len() - 8..];
+        let opts = Opts {
+            tasks: vec![suffix.to_string()],
+            ..Default::default()
+        };
+        let session_events = events(&s, &opts).expect("Should get first task1 session");
+        assert_eq!(session_events.len(), 2, "Start and stop of first session");
+        assert!(session_events.iter().all(|e| e.session == first.session));
+
+        let opts = Opts {
+            tasks: vec!["task1".to_string()],
+            ..Default::default()
+        };
+        let name_events = events(&s, &opts).expect("Should get both task1 sessions");
+        assert_eq!(name_events.len(), 3, "Events from both task1 sessions");
+        assert!(name_events.iter().all(|e| e.name == "task1"));
+    }
+}
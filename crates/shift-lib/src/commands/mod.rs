@@ -21,4 +21,19 @@ mod test {
         };
         start(&shift, &config).unwrap()
     }
+
+    /// Make every following insert fail with a duplicate primary key by
+    /// inserting a copy of the row before the real insert happens.
+    pub fn fail_inserts_with_duplicate_id(shift: &ShiftDb) {
+        shift
+            .conn
+            .execute(
+                "CREATE TRIGGER duplicate_id BEFORE INSERT ON task_events
+                BEGIN
+                    INSERT INTO task_events VALUES (NEW.id, NEW.name, NEW.session, NEW.state, NEW.time);
+                END",
+                [],
+            )
+            .unwrap();
+    }
 }
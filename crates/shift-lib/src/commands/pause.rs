@@ -3,7 +3,7 @@ use std::{error::Error, fmt::Display};
 use chrono::Local;
 use rusqlite::params;
 
-use crate::{Config, ShiftDb, TaskEvent, TaskSession, TaskState};
+use crate::{matches_uid, Config, ShiftDb, TaskEvent, TaskSession, TaskState};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum PauseResumeError {
@@ -17,6 +17,12 @@ pub enum PauseResumeError {
 
 impl Error for PauseResumeError {}
 
+impl From<rusqlite::Error> for PauseResumeError {
+    fn from(err: rusqlite::Error) -> Self {
+        PauseResumeError::SqlError(err.to_string())
+    }
+}
+
 // TODO split pause/resume so we can have better error messages
 impl Display for PauseResumeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -52,7 +58,7 @@ pub fn pause(s: &ShiftDb, args: &Config) -> Result<(), PauseResumeError> {
         Some(uid) => {
             let tasks_with_uid = ongoing
                 .into_iter()
-                .filter(|s| &s.name == uid || s.id.to_string().ends_with(uid))
+                .filter(|s| matches_uid(uid, &s.name, &s.id.to_string()))
                 .collect::<Vec<TaskSession>>();
             match tasks_with_uid.len() {
                 0 => return Err(PauseResumeError::NoTasks),
@@ -65,10 +71,9 @@ pub fn pause(s: &ShiftDb, args: &Config) -> Result<(), PauseResumeError> {
                     return match s.conn.execute(
                         "INSERT INTO task_events VALUES (?1, ?2, ?3, ?4, ?5)",
                         params![pause.id, pause.name, pause.session, pause.state, pause.time],
-                    ) {
-                        Ok(1) => Ok(()),
-                        Ok(_count) => Err(PauseResumeError::UpdateError(t.clone())),
-                        Err(err) => Err(PauseResumeError::SqlError(err.to_string())),
+                    )? {
+                        1 => Ok(()),
+                        _count => Err(PauseResumeError::UpdateError(t.clone())),
                     };
                 }
                 2.. => {
@@ -85,12 +90,10 @@ pub fn pause(s: &ShiftDb, args: &Config) -> Result<(), PauseResumeError> {
                     Some(time),
                     TaskState::Paused,
                 );
-                s.conn
-                    .execute(
-                        "INSERT INTO task_events VALUES (?1, ?2, ?3, ?4, ?5)",
-                        params![e.id, e.name, e.session, e.state, e.time],
-                    )
-                    .expect("SQL statement is vaild");
+                s.conn.execute(
+                    "INSERT INTO task_events VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![e.id, e.name, e.session, e.state, e.time],
+                )?;
             }
         }
         None => match ongoing.len() {
@@ -119,7 +122,7 @@ pub fn resume(s: &ShiftDb, args: &Config) -> Result<(), PauseResumeError> {
         Some(name) => {
             let tasks_with_uid = task_pauses
                 .into_iter()
-                .filter(|s| &s.name == name || s.id.to_string().ends_with(name))
+                .filter(|s| matches_uid(name, &s.name, &s.id.to_string()))
                 .collect::<Vec<TaskSession>>();
 
             match tasks_with_uid.len() {
@@ -141,15 +144,9 @@ pub fn resume(s: &ShiftDb, args: &Config) -> Result<(), PauseResumeError> {
                                 resume.state,
                                 resume.time
                             ],
-                        ) {
-                            Ok(count) => {
-                                if count == 1 {
-                                    Ok(())
-                                } else {
-                                    Err(PauseResumeError::UpdateError(t.clone()))
-                                }
-                            }
-                            Err(err) => Err(PauseResumeError::SqlError(err.to_string())),
+                        )? {
+                            1 => Ok(()),
+                            _count => Err(PauseResumeError::UpdateError(t.clone())),
                         };
                     }
                 }
@@ -169,18 +166,16 @@ pub fn resume(s: &ShiftDb, args: &Config) -> Result<(), PauseResumeError> {
                     Some(time),
                     TaskState::Resumed,
                 );
-                s.conn
-                    .execute(
-                        "INSERT INTO task_events VALUES (?1, ?2, ?3, ?4, ?5)",
-                        params![
-                            resume.id,
-                            resume.name,
-                            resume.session,
-                            resume.state,
-                            resume.time
-                        ],
-                    )
-                    .expect("SQL statement is vaild");
+                s.conn.execute(
+                    "INSERT INTO task_events VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![
+                        resume.id,
+                        resume.name,
+                        resume.session,
+                        resume.state,
+                        resume.time
+                    ],
+                )?;
             }
         }
         None => match task_pauses.len() {
@@ -203,7 +198,7 @@ mod test {
             pause::PauseResumeError,
             sessions::sessions,
             stop::{stop, StopOpts},
-            test::start_with_name,
+            test::{fail_inserts_with_duplicate_id, start_with_name},
         },
         Config, ShiftDb,
     };
@@ -333,4 +328,26 @@ mod test {
             PauseResumeError::NoPauses
         );
     }
+
+    #[test]
+    fn pause_duplicate_id() {
+        let s = ShiftDb::new("");
+        start_with_name(&s, "t1");
+        fail_inserts_with_duplicate_id(&s);
+        let config = Config {
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            pause(&s, &config).expect_err("Can't insert a duplicate id"),
+            PauseResumeError::SqlError(_)
+        ));
+        assert_eq!(
+            s.ongoing_sessions()
+                .iter()
+                .filter(|s| s.is_paused())
+                .count(),
+            0
+        );
+    }
 }
@@ -2,7 +2,7 @@ use std::{collections::HashMap, str::FromStr};
 
 use uuid::Uuid;
 
-use crate::{Config, ShiftDb, TaskEvent, TaskSession};
+use crate::{matches_uid, Config, ShiftDb, TaskEvent, TaskSession};
 
 use crate::commands::events;
 
@@ -47,7 +47,11 @@ pub(crate) fn sessions(s: &ShiftDb, args: &Config) -> anyhow::Result<Vec<TaskSes
     });
 
     let res = if !args.tasks.is_empty() {
-        let filtered = iter.into_iter().filter(|t| args.tasks.contains(&t.name));
+        let filtered = iter.into_iter().filter(|s| {
+            args.tasks
+                .iter()
+                .any(|uid| matches_uid(uid, &s.name, &s.id.to_string()))
+        });
         if args.all {
             filtered.collect()
         } else {
@@ -14,6 +14,12 @@ pub enum StartError {
 
 impl Error for StartError {}
 
+impl From<rusqlite::Error> for StartError {
+    fn from(err: rusqlite::Error) -> Self {
+        StartError::SqlError(err.to_string())
+    }
+}
+
 impl Display for StartError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // TODO
@@ -42,13 +48,12 @@ pub fn start(s: &ShiftDb, args: &StartOpts) -> Result<TaskEvent, StartError> {
     match s.conn.execute(
         "INSERT INTO task_events VALUES (?1, ?2, ?3, ?4, ?5);",
         params![event.id, event.name, event.session, event.state, event.time],
-    ) {
-        Ok(1) => Ok(event),
-        Ok(u) => Err(StartError::SqlError(format!(
+    )? {
+        1 => Ok(event),
+        u => Err(StartError::SqlError(format!(
             "Inserted {} tasks when only expected 1",
             u
         ))),
-        Err(e) => Err(StartError::SqlError(e.to_string())),
     }
 }
 
@@ -57,9 +62,10 @@ mod test {
     use chrono::Local;
 
     use crate::commands::sessions::sessions;
+    use crate::commands::test::fail_inserts_with_duplicate_id;
     use crate::{commands::start::StartOpts, Config, ShiftDb};
 
-    use super::start;
+    use super::{start, StartError};
 
     #[test]
     fn start_time() {
@@ -85,4 +91,21 @@ mod test {
             "Start time not handled"
         );
     }
+
+    #[test]
+    fn start_duplicate_id() {
+        let s = ShiftDb::new("");
+        fail_inserts_with_duplicate_id(&s);
+
+        let config = StartOpts {
+            uid: Some("task1".to_string()),
+            ..Default::default()
+        };
+        let err = start(&s, &config).expect_err("Can't insert a duplicate id");
+        assert!(
+            matches!(err, StartError::SqlError(_)),
+            "Expected sql error, got {err:?}"
+        );
+        assert_eq!(s.ongoing_sessions().len(), 0);
+    }
 }
@@ -3,14 +3,16 @@ use rusqlite::params;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::{ShiftDb, TaskEvent, TaskSession, TaskState};
+use crate::{matches_uid, ShiftDb, TaskEvent, TaskSession, TaskState};
 
-#[derive(Error, Debug, PartialEq, Eq)]
+#[derive(Error, Debug, PartialEq)]
 pub enum Error {
     #[error("Could not decide which task stop from {0:?}")]
     MultipleSessions(Vec<TaskSession>),
     #[error("Could not find any tasks to stop")]
     NoTasks,
+    #[error(transparent)]
+    SqlError(#[from] rusqlite::Error),
 }
 
 #[derive(Debug, Deserialize, Serialize, Default)]
@@ -29,7 +31,7 @@ pub fn stop(s: &ShiftDb, args: &StopOpts) -> Result<(), Error> {
         Some(name) => {
             let ongoing_with_uid = ongoing
                 .into_iter()
-                .filter(|s| &s.name == name || s.id.to_string().ends_with(name))
+                .filter(|s| matches_uid(name, &s.name, &s.id.to_string()))
                 .collect::<Vec<TaskSession>>();
             match ongoing_with_uid.len() {
                 0 => {
@@ -46,13 +48,10 @@ pub fn stop(s: &ShiftDb, args: &StopOpts) -> Result<(), Error> {
                         TaskState::Stopped,
                     );
 
-                    let update_count = s
-                        .conn
-                        .execute(
-                            "INSERT INTO task_events VALUES (?1, ?2, ?3, ?4, ?5)",
-                            params![stop.id, stop.name, stop.session, stop.state, stop.time],
-                        )
-                        .expect("SQL statement is vaild");
+                    let update_count = s.conn.execute(
+                        "INSERT INTO task_events VALUES (?1, ?2, ?3, ?4, ?5)",
+                        params![stop.id, stop.name, stop.session, stop.state, stop.time],
+                    )?;
                     assert_eq!(
                         update_count, 1,
                         "tried inserting one event but {} was inserted",
@@ -73,12 +72,10 @@ pub fn stop(s: &ShiftDb, args: &StopOpts) -> Result<(), Error> {
                     Some(time),
                     TaskState::Stopped,
                 );
-                s.conn
-                    .execute(
-                        "INSERT INTO task_events VALUES (?1, ?2, ?3, ?4, ?5)",
-                        params![event.id, event.name, event.session, event.state, event.time],
-                    )
-                    .expect("SQL statement is vaild");
+                s.conn.execute(
+                    "INSERT INTO task_events VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![event.id, event.name, event.session, event.state, event.time],
+                )?;
             }
         }
         None => match ongoing.len() {
@@ -100,7 +97,10 @@ mod test {
     use crate::commands::sessions::sessions;
     use crate::commands::stop::StopOpts;
     use crate::TaskState;
-    use crate::{commands::test::start_with_name, Config, ShiftDb};
+    use crate::{
+        commands::test::{fail_inserts_with_duplicate_id, start_with_name},
+        Config, ShiftDb,
+    };
 
     use super::Error;
 
@@ -210,4 +210,21 @@ mod test {
             tasks.first()
         );
     }
+
+    #[test]
+    fn stop_duplicate_id() {
+        let s = ShiftDb::new("");
+
+        start_with_name(&s, "task1");
+        fail_inserts_with_duplicate_id(&s);
+
+        let err = stop(&s, &StopOpts::default()).expect_err("Can't insert a duplicate id");
+        match err {
+            Error::SqlError(rusqlite::Error::SqliteFailure(e, _)) => {
+                assert_eq!(e.code, rusqlite::ErrorCode::ConstraintViolation)
+            }
+            _ => panic!("error {}", err),
+        }
+        assert_eq!(s.ongoing_sessions().len(), 1, "task1 should not be stopped");
+    }
 }
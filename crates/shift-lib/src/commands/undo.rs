@@ -5,8 +5,8 @@ use crate::ShiftDb;
 
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error("")]
-    A,
+    #[error(transparent)]
+    SqlError(#[from] rusqlite::Error),
 }
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -14,15 +14,13 @@ pub struct Opts {}
 
 /// return the row count removed
 pub fn undo(s: &ShiftDb, opts: &Opts) -> Result<usize, Error> {
-    Ok(s.conn
-        .execute(
-            "DELETE FROM task_events
+    Ok(s.conn.execute(
+        "DELETE FROM task_events
             WHERE time = (
                 SELECT MAX(time) FROM task_events
             )",
-            [],
-        )
-        .expect("SQL statement is valid"))
+        [],
+    )?)
 }
 
 #[cfg(test)]
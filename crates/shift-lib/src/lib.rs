@@ -47,6 +47,12 @@ impl FromSql for TaskState {
     }
 }
 
+/// Check if `uid` selects a task, either by its exact name or by a suffix of
+/// its session id
+pub(crate) fn matches_uid(uid: &str, name: &str, session: &str) -> bool {
+    name == uid || session.ends_with(uid)
+}
+
 // TODO should this be a pub(crate) type and then expose a type with only public fields?
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TaskEvent {